@@ -1,16 +1,20 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::{PyValueError, PyRuntimeError};
+use pyo3::exceptions::{PyValueError, PyRuntimeError, PyException};
 use pyo3::types::PyModule;
 use math_core::{LatexToMathML, MathCoreConfig, MathDisplay};
-use std::sync::{Mutex, OnceLock};
+use std::cell::Cell;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 use include_dir::{include_dir, Dir};
 
 // Embed the Rules directory at compile time
 static RULES_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/Rules");
 
-// Global state for MathCAT initialization
-static MATHCAT_INITIALIZED: OnceLock<Mutex<bool>> = OnceLock::new();
+thread_local! {
+    // libmathcat keeps its parser/rules state per-thread, so each thread that
+    // calls into it needs its own one-time rules-dir setup, gated by its own flag
+    static THREAD_RULES_INITIALIZED: Cell<bool> = Cell::new(false);
+}
 
 /// Custom error type for mathwords operations
 #[derive(Debug, thiserror::Error)]
@@ -18,8 +22,12 @@ enum MathWordsError {
     #[error("Failed to initialize MathCAT: {0}")]
     InitializationError(String),
 
-    #[error("Failed to convert LaTeX to MathML: {0}")]
-    LatexConversionError(String),
+    #[error("Failed to convert LaTeX to MathML: {message}")]
+    LatexConversionError {
+        message: String,
+        position: Option<usize>,
+        snippet: Option<String>,
+    },
 
     #[error("Failed to convert MathML to speech: {0}")]
     MathMLConversionError(String),
@@ -35,11 +43,85 @@ impl From<MathWordsError> for PyErr {
     fn from(err: MathWordsError) -> PyErr {
         match err {
             MathWordsError::ValidationError(msg) => PyValueError::new_err(msg),
+            MathWordsError::LatexConversionError { message, position, snippet } => {
+                PyErr::new::<LatexSyntaxError, _>((message, position, snippet))
+            }
             _ => PyRuntimeError::new_err(err.to_string()),
         }
     }
 }
 
+/// Raised by `Verbalizer.verbalize` when LaTeX input fails to parse
+///
+/// Exposes `.message` (the underlying math-core error), `.position` (the byte
+/// offset math-core reported, if any), and `.snippet` (the input rendered
+/// with a caret under the failure point, if a position is known).
+/// `Verbalizer.verbalize_batch` does not raise it: a failed entry's
+/// `BatchResult.error` holds only the error message, without position or snippet.
+#[pyclass(extends = PyException)]
+struct LatexSyntaxError {
+    #[pyo3(get)]
+    message: String,
+    #[pyo3(get)]
+    position: Option<usize>,
+    #[pyo3(get)]
+    snippet: Option<String>,
+}
+
+#[pymethods]
+impl LatexSyntaxError {
+    #[new]
+    fn new(message: String, position: Option<usize>, snippet: Option<String>) -> Self {
+        Self { message, position, snippet }
+    }
+
+    fn __str__(&self) -> String {
+        match &self.snippet {
+            Some(snippet) => format!("{}\n{}", self.message, snippet),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// Best-effort extraction of a byte offset from a math-core error's Debug output
+///
+/// math-core doesn't expose a stable structured position API, so we scan its
+/// `{:?}` rendering for the common "position"/"offset"/"index" markers it uses
+/// and fall back to `None` if nothing matches. The digits are read back out of
+/// the same lowercased string we searched, so the slice index is always on one
+/// of its char boundaries (ASCII digits are unaffected by lowercasing, so the
+/// parsed value is identical to what the original string would have given).
+fn extract_error_position(debug_msg: &str) -> Option<usize> {
+    let lower = debug_msg.to_lowercase();
+    const MARKERS: &[&str] = &["position: ", "position ", "pos: ", "offset: ", "index: ", "at position ", "at offset "];
+
+    for marker in MARKERS {
+        if let Some(idx) = lower.find(marker) {
+            let rest = &lower[idx + marker.len()..];
+            let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            if let Ok(position) = digits.parse::<usize>() {
+                return Some(position);
+            }
+        }
+    }
+
+    None
+}
+
+/// Render `input` with a caret on the line below pointing at the byte offset `position`
+fn render_error_snippet(input: &str, position: usize) -> String {
+    let line = input.replace('\n', " ");
+
+    // `position` is a byte offset; convert it to a char count for the caret so
+    // multibyte characters before it don't throw off the alignment.
+    let clamped_byte = position.min(line.len());
+    let boundary = (0..=clamped_byte).rev().find(|&i| line.is_char_boundary(i)).unwrap_or(0);
+    let caret_column = line[..boundary].chars().count();
+
+    let caret_line: String = " ".repeat(caret_column) + "^";
+    format!("{}\n{}", line, caret_line)
+}
+
 /// Extract embedded Rules directory to a temporary location
 /// This is called once on first use and cached
 fn get_rules_directory() -> Result<PathBuf, MathWordsError> {
@@ -59,19 +141,41 @@ fn get_rules_directory() -> Result<PathBuf, MathWordsError> {
 
     // For installed wheels, try to find it relative to the Python package
     // This requires extracting embedded resources to a temp location
-    let temp_dir = std::env::temp_dir().join("mathwords_rules");
-
-    // Only extract if not already present
-    if !temp_dir.exists() {
-        std::fs::create_dir_all(&temp_dir)
-            .map_err(|e| MathWordsError::ResourceError(format!("Failed to create temp directory: {}", e)))?;
+    extract_embedded_rules_once()
+}
 
-        // Extract all embedded files
-        extract_dir(&RULES_DIR, &temp_dir)
-            .map_err(|e| MathWordsError::ResourceError(format!("Failed to extract rules: {}", e)))?;
-    }
+/// Extract the embedded Rules tree to a temp directory at most once per process
+///
+/// Extraction is skipped when the directory already exists (e.g. left over
+/// from an earlier process); its contents are not checked.
+///
+/// Guarded by a process-wide `OnceLock` rather than by a `temp_dir.exists()` check alone:
+/// `process_expressions_parallel` can call into this from several worker
+/// threads at once on a fresh machine, and `exists()` would race with
+/// `create_dir_all` - one thread could observe the directory as already
+/// present (because another thread just created it) and skip extraction
+/// while that other thread is still writing files, handing back a
+/// half-populated Rules tree. `get_or_init` blocks every caller until the
+/// single extraction that runs has finished.
+fn extract_embedded_rules_once() -> Result<PathBuf, MathWordsError> {
+    static RULES_DIR_CACHE: OnceLock<Result<PathBuf, String>> = OnceLock::new();
+
+    RULES_DIR_CACHE
+        .get_or_init(|| {
+            let temp_dir = std::env::temp_dir().join("mathwords_rules");
+
+            if !temp_dir.exists() {
+                std::fs::create_dir_all(&temp_dir)
+                    .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+
+                extract_dir(&RULES_DIR, &temp_dir)
+                    .map_err(|e| format!("Failed to extract rules: {}", e))?;
+            }
 
-    Ok(temp_dir)
+            Ok(temp_dir)
+        })
+        .clone()
+        .map_err(MathWordsError::ResourceError)
 }
 
 /// Recursively extract embedded directory to filesystem
@@ -92,45 +196,148 @@ fn extract_dir(dir: &Dir, target: &PathBuf) -> std::io::Result<()> {
     Ok(())
 }
 
-/// Initialize MathCAT with rules directory
-/// This is thread-safe and will only initialize once
-fn ensure_mathcat_initialized(speech_style: &str) -> Result<(), MathWordsError> {
-    let initialized = MATHCAT_INITIALIZED.get_or_init(|| Mutex::new(false));
-    let mut init_guard = initialized.lock()
-        .map_err(|e| MathWordsError::InitializationError(format!("Mutex lock failed: {}", e)))?;
+/// Enumerate the language subdirectories baked into the embedded Rules tree
+fn get_available_languages() -> Result<Vec<String>, MathWordsError> {
+    let languages_dir = RULES_DIR.get_dir("Languages")
+        .ok_or_else(|| MathWordsError::ResourceError(
+            "Languages directory not found in embedded Rules".to_string()
+        ))?;
+
+    Ok(languages_dir.dirs()
+        .filter_map(|d| d.path().file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect())
+}
 
-    if !*init_guard {
-        let rules_dir = get_rules_directory()?;
+/// Resolve a requested language tag to one actually present under `RULES_DIR/Languages`
+///
+/// Tries the full tag first (e.g. "es-419"), then its base language ("es"),
+/// then falls back to "en". Returns a `ValidationError` if none of those exist.
+fn resolve_language(requested: &str) -> Result<String, MathWordsError> {
+    let available = get_available_languages()?;
+    resolve_language_from(&available, requested)
+}
 
-        // Initialize MathCAT - wrap in catch_unwind to prevent panics
-        let init_result = std::panic::catch_unwind(|| {
-            libmathcat::interface::set_rules_dir(rules_dir.to_string_lossy().to_string())
-                .map_err(|e| format!("Failed to set rules directory: {:?}", e))?;
+/// Pure matching logic behind [`resolve_language`], split out so it can be
+/// unit-tested without the embedded `RULES_DIR`
+fn resolve_language_from(available: &[String], requested: &str) -> Result<String, MathWordsError> {
+    let mut candidates = vec![requested.to_string()];
+    if let Some((base, _)) = requested.split_once('-') {
+        candidates.push(base.to_string());
+    }
+    candidates.push("en".to_string());
+
+    for candidate in candidates {
+        // Match case-insensitively, but return the directory's own casing -
+        // MathCAT's language lookup is case-sensitive, so a requested "ES"
+        // must resolve to the "es" rules dir, not a literal "ES" tag.
+        if let Some(actual) = available.iter().find(|lang| lang.eq_ignore_ascii_case(&candidate)) {
+            return Ok(actual.clone());
+        }
+    }
 
-            libmathcat::interface::set_preference("Language".to_string(), "en".to_string())
-                .map_err(|e| format!("Failed to set language: {:?}", e))?;
+    Err(MathWordsError::ValidationError(format!(
+        "No rules available for language '{}' (tried full tag, base language, and \"en\" fallback)",
+        requested
+    )))
+}
 
-            libmathcat::interface::set_preference("SpeechStyle".to_string(), speech_style.to_string())
-                .map_err(|e| format!("Failed to set speech style: {:?}", e))?;
+/// Validate and normalize a requested speech-markup mode
+fn validate_markup(markup: &str) -> Result<&'static str, MathWordsError> {
+    match markup {
+        "none" => Ok("none"),
+        "ssml" => Ok("ssml"),
+        "sapi5" => Ok("sapi5"),
+        other => Err(MathWordsError::ValidationError(format!(
+            "Invalid markup mode '{}': expected \"none\", \"ssml\", or \"sapi5\"",
+            other
+        ))),
+    }
+}
 
-            Ok::<(), String>(())
+/// Map a markup token to the exact value MathCAT's `TTS` preference expects
+///
+/// The public-facing tokens ("none"/"ssml"/"sapi5") are shared with
+/// `validate_markup`, but MathCAT's `TTS` preference takes its markup
+/// values uppercase ("SSML"/"SAPI5"); "none" turns markup off and is
+/// lowercase in both places.
+fn mathcat_tts_value(markup: &str) -> &'static str {
+    match markup {
+        "ssml" => "SSML",
+        "sapi5" => "SAPI5",
+        _ => "none",
+    }
+}
+
+/// Apply the language/style/markup/bookmark preferences to MathCAT
+///
+/// Wrapped in `catch_unwind` just like the one-time rules-dir setup below:
+/// this runs on every call, including the 2nd+ expression a batch worker
+/// thread handles, so a panic here must fail only that expression instead
+/// of unwinding the whole worker and leaving the rest of its chunk as an
+/// opaque "no result" error.
+fn apply_mathcat_preferences(speech_style: &str, language: &str, markup: &str) -> Result<(), MathWordsError> {
+    let bookmarks = (markup != "none").to_string();
+
+    let result = std::panic::catch_unwind(|| {
+        libmathcat::interface::set_preference("Language".to_string(), language.to_string())
+            .map_err(|e| format!("Failed to set language: {:?}", e))?;
+
+        libmathcat::interface::set_preference("SpeechStyle".to_string(), speech_style.to_string())
+            .map_err(|e| format!("Failed to set speech style: {:?}", e))?;
+
+        libmathcat::interface::set_preference("TTS".to_string(), mathcat_tts_value(markup).to_string())
+            .map_err(|e| format!("Failed to set speech markup mode: {:?}", e))?;
+
+        libmathcat::interface::set_preference("Bookmark".to_string(), bookmarks)
+            .map_err(|e| format!("Failed to set bookmark preference: {:?}", e))?;
+
+        Ok::<(), String>(())
+    });
+
+    match result {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(MathWordsError::InitializationError(e)),
+        Err(_) => Err(MathWordsError::InitializationError(
+            "MathCAT preference update panicked".to_string()
+        )),
+    }
+}
+
+/// Initialize MathCAT for the calling thread
+///
+/// libmathcat stores its parser/rules state per-thread, so the one-time
+/// rules-dir setup is gated by a thread-local flag rather than a shared
+/// mutex: each thread that ever calls into libmathcat does this once, and
+/// every call (first or not) re-applies the language/style/markup
+/// preferences so a `Verbalizer`'s configuration never depends on what a
+/// previous call on this thread happened to leave behind.
+fn ensure_mathcat_initialized(speech_style: &str, language: &str, markup: &str) -> Result<(), MathWordsError> {
+    let needs_rules_dir = THREAD_RULES_INITIALIZED.with(|flag| !flag.get());
+
+    if needs_rules_dir {
+        let rules_dir = get_rules_directory()?;
+
+        // Wrap in catch_unwind to prevent panics
+        let set_rules_dir_result = std::panic::catch_unwind(|| {
+            libmathcat::interface::set_rules_dir(rules_dir.to_string_lossy().to_string())
+                .map_err(|e| format!("Failed to set rules directory: {:?}", e))
         });
 
-        match init_result {
-            Ok(Ok(())) => {
-                *init_guard = true;
-                Ok(())
-            }
-            Ok(Err(e)) => Err(MathWordsError::InitializationError(e)),
-            Err(_) => Err(MathWordsError::InitializationError(
+        match set_rules_dir_result {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => return Err(MathWordsError::InitializationError(e)),
+            Err(_) => return Err(MathWordsError::InitializationError(
                 "MathCAT initialization panicked".to_string()
             )),
         }
-    } else {
-        // Already initialized, just update speech style if needed
-        libmathcat::interface::set_preference("SpeechStyle".to_string(), speech_style.to_string())
-            .map_err(|e| MathWordsError::InitializationError(format!("Failed to update speech style: {:?}", e)))?;
+
+        apply_mathcat_preferences(speech_style, language, markup)?;
+        THREAD_RULES_INITIALIZED.with(|flag| flag.set(true));
         Ok(())
+    } else {
+        // Rules dir already set up on this thread, just refresh the per-call preferences
+        apply_mathcat_preferences(speech_style, language, markup)
     }
 }
 
@@ -155,15 +362,25 @@ fn latex_to_mathml(latex: &str, display_mode: bool) -> Result<String, MathWordsE
 
     match result {
         Ok(Ok(mathml)) => Ok(mathml),
-        Ok(Err(e)) => Err(MathWordsError::LatexConversionError(e)),
-        Err(_) => Err(MathWordsError::LatexConversionError(
-            "LaTeX conversion panicked".to_string()
-        )),
+        Ok(Err(message)) => {
+            let position = extract_error_position(&message);
+            let snippet = position.map(|p| render_error_snippet(latex, p));
+            Err(MathWordsError::LatexConversionError { message, position, snippet })
+        }
+        Err(_) => Err(MathWordsError::LatexConversionError {
+            message: "LaTeX conversion panicked".to_string(),
+            position: None,
+            snippet: None,
+        }),
     }
 }
 
 /// Convert MathML to speech text
-fn mathml_to_speech(mathml: &str) -> Result<String, MathWordsError> {
+///
+/// When `markup` is `"ssml"`, MathCAT's output is a fragment of `<break>`/`<prosody>`
+/// tags, not a standalone document, so it's wrapped in a `<speak>` root here. `"sapi5"`
+/// markup has no such document root and is returned as MathCAT produces it.
+fn mathml_to_speech(mathml: &str, markup: &str) -> Result<String, MathWordsError> {
     // Wrap in catch_unwind to prevent panics
     let result = std::panic::catch_unwind(|| {
         libmathcat::interface::set_mathml(mathml.to_string())
@@ -174,7 +391,7 @@ fn mathml_to_speech(mathml: &str) -> Result<String, MathWordsError> {
     });
 
     match result {
-        Ok(Ok(speech)) => Ok(speech),
+        Ok(Ok(speech)) => Ok(wrap_ssml_document(speech, markup)),
         Ok(Err(e)) => Err(MathWordsError::MathMLConversionError(e)),
         Err(_) => Err(MathWordsError::MathMLConversionError(
             "MathML to speech conversion panicked".to_string()
@@ -182,95 +399,214 @@ fn mathml_to_speech(mathml: &str) -> Result<String, MathWordsError> {
     }
 }
 
-/// Main verbalize function exposed to Python
-///
-/// Converts LaTeX or MathML input to English verbalized text.
-///
-/// Args:
-///     input_str: The LaTeX or MathML string to convert
-///     is_mathml: If True, input is treated as MathML; if False, as LaTeX (default: False)
-///     speech_style: Speech style for verbalization - "ClearSpeak", "SimpleSpeak", etc. (default: "ClearSpeak")
-///     display_mode: For LaTeX input, whether to treat as display (block) mode (default: False)
-///
-/// Returns:
-///     Verbalized English text string
-///
-/// Raises:
-///     ValueError: If input is invalid or empty
-///     RuntimeError: If conversion fails
-#[pyfunction]
-#[pyo3(signature = (input_str, is_mathml=false, speech_style="ClearSpeak", display_mode=false))]
-fn verbalize(
-    py: Python,
-    input_str: &str,
-    is_mathml: bool,
-    speech_style: &str,
-    display_mode: bool,
-) -> PyResult<String> {
-    // Validate input
-    if input_str.trim().is_empty() {
-        return Err(MathWordsError::ValidationError("Input string is empty".to_string()).into());
-    }
-
-    // Release GIL for CPU-bound work
-    Python::detach(py, || {
-        // Ensure MathCAT is initialized
-        ensure_mathcat_initialized(speech_style)?;
-
-        // Convert to MathML if needed
-        let mathml = if is_mathml {
-            input_str.to_string()
-        } else {
-            latex_to_mathml(input_str, display_mode)?
-        };
-
-        // Convert MathML to speech
-        mathml_to_speech(&mathml)
-    }).map_err(|e: MathWordsError| e.into())
+/// Wrap `speech` in a `<speak>` root element when `markup` is `"ssml"` and it isn't already wrapped
+fn wrap_ssml_document(speech: String, markup: &str) -> String {
+    if markup == "ssml" && !speech.trim_start().starts_with("<speak") {
+        format!("<speak>{}</speak>", speech)
+    } else {
+        speech
+    }
 }
 
-/// Batch verbalize multiple expressions
+/// Run `convert` over `expressions` across up to `num_threads` worker threads
 ///
-/// Args:
-///     expressions: List of (input_str, is_mathml) tuples
-///     speech_style: Speech style for verbalization (default: "ClearSpeak")
-///     display_mode: For LaTeX inputs, default display mode (default: False)
-///
-/// Returns:
-///     List of verbalized English text strings
-#[pyfunction]
-#[pyo3(signature = (expressions, speech_style="ClearSpeak", display_mode=false))]
-fn verbalize_batch(
-    py: Python,
-    expressions: Vec<(String, Option<bool>)>,
-    speech_style: &str,
-    display_mode: bool,
-) -> PyResult<Vec<String>> {
+/// Input order is preserved in the returned `Vec` regardless of how the work
+/// was split up, and each expression's outcome is isolated: an `Err` returned
+/// for one slot never stops the others from being processed or reported. A
+/// panic in `convert` ends that worker, so the panicking slot and the rest of
+/// its chunk are reported as "did not produce a result" errors. Split out
+/// from `Verbalizer::verbalize_batch` so the chunking/ordering/isolation
+/// behavior can be unit-tested with a fake `convert` instead of libmathcat.
+fn process_expressions_parallel<F>(
+    expressions: &[(String, Option<bool>)],
+    num_threads: usize,
+    convert: F,
+) -> Vec<Result<String, MathWordsError>>
+where
+    F: Fn(&str, bool) -> Result<String, MathWordsError> + Sync,
+{
     if expressions.is_empty() {
-        return Err(MathWordsError::ValidationError("Expression list is empty".to_string()).into());
+        return Vec::new();
     }
 
-    Python::detach(py, || {
-        // Initialize once for the batch
-        ensure_mathcat_initialized(speech_style)?;
+    let num_threads = num_threads.max(1).min(expressions.len());
+    let chunk_size = expressions.len().div_ceil(num_threads);
+
+    let mut results: Vec<Option<Result<String, MathWordsError>>> =
+        (0..expressions.len()).map(|_| None).collect();
 
-        let mut results = Vec::with_capacity(expressions.len());
+    std::thread::scope(|scope| {
+        let expr_chunks = expressions.chunks(chunk_size);
+        let result_chunks = results.chunks_mut(chunk_size);
+        let convert = &convert;
 
-        for (input_str, is_mathml_opt) in expressions {
-            let is_mathml = is_mathml_opt.unwrap_or(false);
+        let handles: Vec<_> = expr_chunks.zip(result_chunks).map(|(expr_chunk, result_chunk)| {
+            scope.spawn(move || {
+                for (slot, (input_str, is_mathml_opt)) in result_chunk.iter_mut().zip(expr_chunk.iter()) {
+                    let is_mathml = is_mathml_opt.unwrap_or(false);
+                    *slot = Some(convert(input_str, is_mathml));
+                }
+            })
+        }).collect();
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+    });
+
+    results.into_iter()
+        .map(|slot| slot.unwrap_or_else(|| Err(MathWordsError::InitializationError(
+            "Worker thread did not produce a result".to_string()
+        ))))
+        .collect()
+}
+
+/// A reusable, self-contained verbalization configuration
+///
+/// Unlike the module-level preference globals this replaces, a `Verbalizer`
+/// stores its `speech_style`/`language`/`display_mode`/`markup` on the
+/// instance and re-applies the full preference set immediately before every
+/// `set_mathml`/`get_spoken_text` pair, so two instances configured
+/// differently never stomp on each other's settings mid-conversion.
+#[pyclass]
+struct Verbalizer {
+    speech_style: String,
+    language: String,
+    display_mode: bool,
+    markup: String,
+}
+
+#[pymethods]
+impl Verbalizer {
+    /// Create a new Verbalizer with a fixed configuration
+    ///
+    /// Args:
+    ///     speech_style: Speech style for verbalization - "ClearSpeak", "SimpleSpeak", etc. (default: "ClearSpeak")
+    ///     language: BCP-47-style language tag, e.g. "en", "es-419", "fr-CA" (default: "en").
+    ///         Falls back from the full tag to the base language to "en" if no
+    ///         matching rules are bundled.
+    ///     display_mode: For LaTeX input, whether to treat as display (block) mode (default: False)
+    ///     markup: Speech-markup mode - "none", "ssml", or "sapi5" (default: "none")
+    ///
+    /// Raises:
+    ///     ValueError: If markup is not recognized or no language in the fallback chain resolves
+    #[new]
+    #[pyo3(signature = (speech_style="ClearSpeak", language="en", display_mode=false, markup="none"))]
+    fn new(speech_style: &str, language: &str, display_mode: bool, markup: &str) -> PyResult<Self> {
+        let resolved_language = resolve_language(language)?;
+        let markup = validate_markup(markup)?;
+
+        Ok(Self {
+            speech_style: speech_style.to_string(),
+            language: resolved_language,
+            display_mode,
+            markup: markup.to_string(),
+        })
+    }
+
+    /// Verbalize a single LaTeX or MathML expression using this instance's configuration
+    ///
+    /// Args:
+    ///     input_str: The LaTeX or MathML string to convert
+    ///     is_mathml: If True, input is treated as MathML; if False, as LaTeX (default: False)
+    ///
+    /// Returns:
+    ///     Verbalized text string in the resolved language, optionally wrapped in speech markup
+    ///
+    /// Raises:
+    ///     ValueError: If input is empty
+    ///     LatexSyntaxError: If LaTeX input fails to parse
+    ///     RuntimeError: If MathCAT setup or MathML-to-speech conversion fails
+    #[pyo3(signature = (input_str, is_mathml=false))]
+    fn verbalize(&self, py: Python, input_str: &str, is_mathml: bool) -> PyResult<String> {
+        if input_str.trim().is_empty() {
+            return Err(MathWordsError::ValidationError("Input string is empty".to_string()).into());
+        }
+
+        // Release GIL for CPU-bound work
+        Python::detach(py, || {
+            ensure_mathcat_initialized(&self.speech_style, &self.language, &self.markup)?;
 
             let mathml = if is_mathml {
-                input_str
+                input_str.to_string()
             } else {
-                latex_to_mathml(&input_str, display_mode)?
+                latex_to_mathml(input_str, self.display_mode)?
             };
 
-            let speech = mathml_to_speech(&mathml)?;
-            results.push(speech);
+            mathml_to_speech(&mathml, &self.markup)
+        }).map_err(|e: MathWordsError| e.into())
+    }
+
+    /// Batch verbalize multiple expressions in parallel using this instance's configuration
+    ///
+    /// The batch is split across a worker pool; each worker thread runs its own
+    /// one-time MathCAT setup (libmathcat's parser/rules state is per-thread) and
+    /// then converts its share of the expressions. A bad expression produces a
+    /// failed `BatchResult` entry instead of aborting the rest of the batch.
+    ///
+    /// Args:
+    ///     expressions: List of (input_str, is_mathml) tuples
+    ///     num_threads: Maximum number of worker threads to use (default: available parallelism)
+    ///
+    /// Returns:
+    ///     List of `BatchResult`, one per input expression, in the same order
+    #[pyo3(signature = (expressions, num_threads=None))]
+    fn verbalize_batch(
+        &self,
+        py: Python,
+        expressions: Vec<(String, Option<bool>)>,
+        num_threads: Option<usize>,
+    ) -> PyResult<Vec<Py<BatchResult>>> {
+        if expressions.is_empty() {
+            return Err(MathWordsError::ValidationError("Expression list is empty".to_string()).into());
         }
 
-        Ok(results)
-    }).map_err(|e: MathWordsError| e.into())
+        let speech_style = self.speech_style.clone();
+        let language = self.language.clone();
+        let markup = self.markup.clone();
+        let display_mode = self.display_mode;
+
+        let outcomes: Vec<Result<String, MathWordsError>> = Python::detach(py, || {
+            let num_threads = num_threads
+                .unwrap_or_else(|| std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1));
+
+            process_expressions_parallel(&expressions, num_threads, |input_str, is_mathml| {
+                ensure_mathcat_initialized(&speech_style, &language, &markup)?;
+
+                let mathml = if is_mathml {
+                    input_str.to_string()
+                } else {
+                    latex_to_mathml(input_str, display_mode)?
+                };
+
+                mathml_to_speech(&mathml, &markup)
+            })
+        });
+
+        outcomes.into_iter()
+            .map(|outcome| {
+                let result = match outcome {
+                    Ok(text) => BatchResult { ok: true, text: Some(text), error: None },
+                    Err(e) => BatchResult { ok: false, text: None, error: Some(e.to_string()) },
+                };
+                Py::new(py, result)
+            })
+            .collect()
+    }
+}
+
+/// The outcome of converting a single expression within a `Verbalizer.verbalize_batch` call
+#[pyclass]
+struct BatchResult {
+    /// Whether this expression converted successfully
+    #[pyo3(get)]
+    ok: bool,
+    /// The verbalized text, present when `ok` is True
+    #[pyo3(get)]
+    text: Option<String>,
+    /// The error message, present when `ok` is False
+    #[pyo3(get)]
+    error: Option<String>,
 }
 
 /// Get information about available speech styles
@@ -282,12 +618,24 @@ fn get_speech_styles() -> PyResult<Vec<String>> {
     ])
 }
 
+/// Get the language tags that have rules bundled in this build
+///
+/// Returns:
+///     List of language tags (e.g. "en", "es", "fr-CA") found under the
+///     embedded Rules/Languages directory
+#[pyfunction]
+fn get_languages() -> PyResult<Vec<String>> {
+    Ok(get_available_languages()?)
+}
+
 /// Python module definition
 #[pymodule]
 fn mathwords(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(verbalize, m)?)?;
-    m.add_function(wrap_pyfunction!(verbalize_batch, m)?)?;
+    m.add_class::<Verbalizer>()?;
+    m.add_class::<BatchResult>()?;
+    m.add_class::<LatexSyntaxError>()?;
     m.add_function(wrap_pyfunction!(get_speech_styles, m)?)?;
+    m.add_function(wrap_pyfunction!(get_languages, m)?)?;
 
     // Add module metadata
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
@@ -295,3 +643,161 @@ fn mathwords(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn available_languages() -> Vec<String> {
+        vec!["en".to_string(), "es".to_string(), "fr-CA".to_string()]
+    }
+
+    #[test]
+    fn resolve_language_from_prefers_the_full_tag() {
+        let available = available_languages();
+        assert_eq!(resolve_language_from(&available, "fr-CA").unwrap(), "fr-CA");
+    }
+
+    #[test]
+    fn resolve_language_from_falls_back_to_base_language() {
+        let available = available_languages();
+        assert_eq!(resolve_language_from(&available, "es-419").unwrap(), "es");
+    }
+
+    #[test]
+    fn resolve_language_from_falls_back_to_en() {
+        let available = available_languages();
+        assert_eq!(resolve_language_from(&available, "de-DE").unwrap(), "en");
+    }
+
+    #[test]
+    fn resolve_language_from_returns_the_available_tags_own_casing() {
+        let available = available_languages();
+        assert_eq!(resolve_language_from(&available, "ES").unwrap(), "es");
+    }
+
+    #[test]
+    fn resolve_language_from_errors_when_nothing_in_the_chain_resolves() {
+        let available = vec!["es".to_string()];
+        let err = resolve_language_from(&available, "de-DE").unwrap_err();
+        assert!(matches!(err, MathWordsError::ValidationError(_)));
+    }
+
+    #[test]
+    fn validate_markup_accepts_the_known_modes() {
+        assert_eq!(validate_markup("none").unwrap(), "none");
+        assert_eq!(validate_markup("ssml").unwrap(), "ssml");
+        assert_eq!(validate_markup("sapi5").unwrap(), "sapi5");
+    }
+
+    #[test]
+    fn validate_markup_rejects_unknown_modes() {
+        let err = validate_markup("espeak").unwrap_err();
+        assert!(matches!(err, MathWordsError::ValidationError(_)));
+    }
+
+    #[test]
+    fn wrap_ssml_document_adds_a_speak_root_only_for_ssml() {
+        assert_eq!(wrap_ssml_document("hello".to_string(), "ssml"), "<speak>hello</speak>");
+        assert_eq!(wrap_ssml_document("hello".to_string(), "sapi5"), "hello");
+        assert_eq!(wrap_ssml_document("hello".to_string(), "none"), "hello");
+    }
+
+    #[test]
+    fn wrap_ssml_document_does_not_double_wrap() {
+        let already_wrapped = "<speak>hello</speak>".to_string();
+        assert_eq!(wrap_ssml_document(already_wrapped.clone(), "ssml"), already_wrapped);
+    }
+
+    #[test]
+    fn extract_error_position_reads_common_marker_styles() {
+        assert_eq!(extract_error_position("ParseError { position: 12, .. }"), Some(12));
+        assert_eq!(extract_error_position("error at offset 7"), Some(7));
+        assert_eq!(extract_error_position("SyntaxError(pos: 3)"), Some(3));
+        assert_eq!(extract_error_position("unparseable input"), None);
+    }
+
+    #[test]
+    fn extract_error_position_does_not_panic_on_multibyte_input() {
+        // "µ" lowercases to itself but other scripts can change byte length;
+        // this just needs to not panic on a char boundary mismatch.
+        assert_eq!(extract_error_position("ΣError at position 4 in µs"), Some(4));
+    }
+
+    #[test]
+    fn render_error_snippet_places_the_caret_at_the_byte_position() {
+        let snippet = render_error_snippet("1+2", 2);
+        let mut lines = snippet.lines();
+        assert_eq!(lines.next(), Some("1+2"));
+        assert_eq!(lines.next(), Some("  ^"));
+    }
+
+    #[test]
+    fn render_error_snippet_converts_byte_offsets_past_multibyte_chars_to_char_columns() {
+        // "µ" is 2 bytes in UTF-8, so a byte offset of 2 lands right after it;
+        // the caret column should be 1 (one character in), not 2.
+        let snippet = render_error_snippet("µx", 2);
+        let mut lines = snippet.lines();
+        assert_eq!(lines.next(), Some("µx"));
+        assert_eq!(lines.next(), Some(" ^"));
+    }
+
+    #[test]
+    fn render_error_snippet_clamps_an_out_of_range_position() {
+        let snippet = render_error_snippet("ab", 99);
+        assert!(snippet.lines().nth(1).unwrap().len() <= "ab ".len());
+    }
+
+    #[test]
+    fn process_expressions_parallel_preserves_input_order() {
+        let expressions: Vec<(String, Option<bool>)> = (0..20)
+            .map(|i| (i.to_string(), Some(false)))
+            .collect();
+
+        let outcomes = process_expressions_parallel(&expressions, 4, |input_str, _is_mathml| {
+            Ok(format!("spoken-{}", input_str))
+        });
+
+        let expected: Vec<String> = (0..20).map(|i| format!("spoken-{}", i)).collect();
+        let actual: Vec<String> = outcomes.into_iter().map(|o| o.unwrap()).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn process_expressions_parallel_isolates_a_single_bad_expression() {
+        let expressions: Vec<(String, Option<bool>)> = vec![
+            ("ok-0".to_string(), Some(false)),
+            ("bad".to_string(), Some(false)),
+            ("ok-2".to_string(), Some(false)),
+        ];
+
+        let outcomes = process_expressions_parallel(&expressions, 3, |input_str, _is_mathml| {
+            if input_str == "bad" {
+                Err(MathWordsError::LatexConversionError {
+                    message: "boom".to_string(),
+                    position: None,
+                    snippet: None,
+                })
+            } else {
+                Ok(input_str.to_string())
+            }
+        });
+
+        assert_eq!(outcomes.len(), 3);
+        assert_eq!(outcomes[0].as_ref().unwrap(), "ok-0");
+        assert!(outcomes[1].is_err());
+        assert_eq!(outcomes[2].as_ref().unwrap(), "ok-2");
+    }
+
+    #[test]
+    fn process_expressions_parallel_clamps_num_threads_to_the_batch_size() {
+        let expressions: Vec<(String, Option<bool>)> = vec![("only".to_string(), Some(false))];
+
+        let outcomes = process_expressions_parallel(&expressions, 8, |input_str, _is_mathml| {
+            Ok(input_str.to_string())
+        });
+
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].as_ref().unwrap(), "only");
+    }
+}